@@ -1,28 +1,63 @@
-use std::io::Write;
-use std::process::Command;
-use std::time::Duration;
+use std::cell::{Cell, RefCell};
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::process::{Command, Stdio};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use phf::phf_map;
 use dbus::blocking::Connection;
+use dbus_crossroads::Crossroads;
 use tempfile::NamedTempFile;
 use handlebars::Handlebars;
 use serde_json::json;
 use lexopt::Parser;
 
-const SCRIPT_HEADER: &str = r#"
-print("{{{marker}}} START");
+/// Well-known interface exposed by kdotool's back channel. Each process
+/// registers a service name (`org.kde.kdotool.<pid>`), and each script run
+/// gets its own object path under it, so the KWin script can report output straight back over the session bus
+/// instead of us scraping `journalctl` for it.
+const BACK_CHANNEL_INTERFACE: &str = "org.kde.kdotool.Output";
+
+/// How long we're willing to wait for the script to report its `FINISH`
+/// line back over the back channel before giving up.
+const BACK_CHANNEL_TIMEOUT: Duration = Duration::from_secs(10);
 
+const SCRIPT_HEADER: &str = r#"
 function output_debug(message) {
     {{#if debug}}
-    print("{{{marker}}} DEBUG", message);
+    callDBus("{{{back_channel_service}}}", "{{{back_channel_path}}}", "{{{back_channel_interface}}}", "Emit", "DEBUG", String(message));
     {{/if}}
 }
 
 function output_error(message) {
-    print("{{{marker}}} ERROR", message);
+    callDBus("{{{back_channel_service}}}", "{{{back_channel_path}}}", "{{{back_channel_interface}}}", "Emit", "ERROR", String(message));
 }
 
 function output_result(message) {
-    print("{{{marker}}} RESULT", message);
+    callDBus("{{{back_channel_service}}}", "{{{back_channel_path}}}", "{{{back_channel_interface}}}", "Emit", "RESULT", String(message));
+}
+
+// Resolves an xdotool-style geometry component ("320", "+10", "-10", "50%")
+// against the window's current position/size and the extent (width or
+// height) of its screen, for windowmove/windowsize.
+function resolve_geometry(spec, current, extent) {
+    spec = String(spec);
+    var relative = false;
+    var sign = 1;
+    if (spec.charAt(0) === "+" || spec.charAt(0) === "-") {
+        relative = true;
+        sign = (spec.charAt(0) === "-") ? -1 : 1;
+        spec = spec.substring(1);
+    }
+    var value;
+    if (spec.charAt(spec.length - 1) === "%") {
+        value = extent * (parseFloat(spec.substring(0, spec.length - 1)) / 100);
+    } else {
+        value = parseFloat(spec);
+    }
+    return relative ? current + sign * value : value;
 }
 
 function run() {
@@ -32,9 +67,13 @@ function run() {
 const SCRIPT_FOOTER: &str = r#"
 }
 
-run();
+try {
+    run();
+} catch (e) {
+    callDBus("{{{back_channel_service}}}", "{{{back_channel_path}}}", "{{{back_channel_interface}}}", "Emit", "ERROR", String(e));
+}
 
-print("{{{marker}}} FINISH");
+callDBus("{{{back_channel_service}}}", "{{{back_channel_path}}}", "{{{back_channel_interface}}}", "Emit", "FINISH", "");
 "#;
 
 const STEP_SEARCH : &str = r#"
@@ -119,6 +158,195 @@ const STEP_LAST_OUTPUT : &str = r#"
     }
 "#;
 
+/// Resident script the daemon loads once at startup (and never stops) so its
+/// `connect()` handler keeps reporting focus changes over the back channel
+/// for as long as the daemon is running. This is how `kdotool lru` and
+/// `kdotool switch-urgent-or-lru` learn about the most-recently-used window
+/// without polling.
+const MONITOR_SCRIPT: &str = r#"
+function emit(kind, message) {
+    callDBus("{{{back_channel_service}}}", "{{{back_channel_path}}}", "{{{back_channel_interface}}}", "Emit", kind, message);
+}
+
+{{#if kde5}}
+workspace.clientActivated.connect(function(w) {
+{{else}}
+workspace.windowActivated.connect(function(w) {
+{{/if}}
+    if (w) {
+        emit("ACTIVATED", w.internalId);
+    }
+});
+
+{{#if kde5}}
+workspace.clientRemoved.connect(function(w) {
+{{else}}
+workspace.windowRemoved.connect(function(w) {
+{{/if}}
+    if (w) {
+        emit("REMOVED", w.internalId);
+    }
+});
+"#;
+
+/// Populates `window_stack` from the daemon's MRU list (oldest-focused
+/// first, most recently focused last), in the same order `kdotool lru`
+/// reports it. `mru_ids_json` is a JSON array of `internalId`s taken from
+/// daemon state; ids no longer present in `workspace.clientList()`/
+/// `windowList()` (closed windows) are skipped.
+const STEP_LRU_PUSH: &str = r#"
+    output_debug("STEP lru")
+    {{#if kde5}}
+    t = workspace.clientList();
+    {{else}}
+    t = workspace.windowList();
+    {{/if}}
+    var mru_ids = {{{mru_ids_json}}};
+    window_stack = [];
+    for (var i=0; i<mru_ids.length; i++) {
+        for (var j=0; j<t.length; j++) {
+            if (t[j].internalId == mru_ids[i]) {
+                window_stack.push(t[j]);
+                break;
+            }
+        }
+    }
+"#;
+
+/// Activates an urgent (demanding-attention) window if one exists -- the
+/// first such window in KWin's window list, not the one that became urgent
+/// most recently -- otherwise the previously focused window still open (the second
+/// entry from the end of the daemon's MRU list, since the last entry is the
+/// currently focused window). Filtering `mru_ids` against the windows that
+/// currently exist means a stale/closed entry is skipped rather than
+/// silently doing nothing.
+const STEP_SWITCH_URGENT_OR_LRU: &str = r#"
+    output_debug("STEP switch-urgent-or-lru")
+    {{#if kde5}}
+    t = workspace.clientList();
+    {{else}}
+    t = workspace.windowList();
+    {{/if}}
+    var mru_ids = {{{mru_ids_json}}};
+    var target = null;
+    for (var i=0; i<t.length; i++) {
+        if (t[i].demandsAttention) {
+            target = t[i];
+            break;
+        }
+    }
+    if (!target) {
+        var present = [];
+        for (var i=0; i<mru_ids.length; i++) {
+            for (var j=0; j<t.length; j++) {
+                if (t[j].internalId == mru_ids[i]) {
+                    present.push(t[j]);
+                    break;
+                }
+            }
+        }
+        if (present.length > 1) {
+            target = present[present.length - 2];
+        }
+    }
+    window_stack = target ? [target] : [];
+    if (target) {
+        workspace.setActiveWindow(target);
+    }
+"#;
+
+/// Lists every window as `internalId<TAB>label` RESULT lines so
+/// `selectwindow` can show the user a menu and still recover the id of
+/// whichever line they picked.
+const STEP_SELECTWINDOW_CANDIDATES: &str = r#"
+    {{#if kde5}}
+    t = workspace.clientList();
+    {{else}}
+    t = workspace.windowList();
+    {{/if}}
+    for (var i=0; i<t.length; i++) {
+        var w = t[i];
+        output_result(w.internalId + "\t" + w.caption + " (" + w.resourceClass + ")");
+    }
+"#;
+
+/// Menu entries are numbered so the chosen candidate can be recovered by
+/// position rather than by matching the label text back against the
+/// candidate list, which would pick the wrong window whenever two windows
+/// share the same caption and class (e.g. two tabs of the same app).
+fn number_candidate_labels(labels: &[&str]) -> Vec<String> {
+    labels.iter().enumerate().map(|(i, label)| format!("{}: {}", i, label)).collect()
+}
+
+/// Recover the index that `number_candidate_labels` prefixed onto the menu
+/// line the user picked.
+fn parse_numbered_selection(selection: &str) -> anyhow::Result<usize> {
+    selection.split_once(": ")
+        .and_then(|(prefix, _)| prefix.parse::<usize>().ok())
+        .ok_or_else(|| anyhow::anyhow!("Selection '{}' was not one of the offered windows", selection))
+}
+
+const STEP_SELECTWINDOW_PUSH: &str = r#"
+    output_debug("STEP selectwindow {{{window_id}}}")
+    {{#if kde5}}
+    t = workspace.clientList();
+    {{else}}
+    t = workspace.windowList();
+    {{/if}}
+    window_stack = [];
+    for (var i=0; i<t.length; i++) {
+        if (t[i].internalId == "{{{window_id}}}") {
+            window_stack.push(t[i]);
+            break;
+        }
+    }
+"#;
+
+/// Action bodies for `windowmove`/`windowsize`. Unlike the static `ACTIONS`
+/// map, these are rendered per-invocation since the coordinates are taken
+/// from the command line rather than being fixed.
+const ACTION_WINDOWMOVE: &str = r#"(function() {
+    var area = workspace.clientArea(KWin.PlacementArea, w.screen, workspace.currentDesktop);
+    var g = w.frameGeometry;
+    g.x = resolve_geometry("{{{x}}}", g.x, area.width);
+    g.y = resolve_geometry("{{{y}}}", g.y, area.height);
+    w.frameGeometry = g;
+})();"#;
+
+const ACTION_WINDOWSIZE: &str = r#"(function() {
+    var area = workspace.clientArea(KWin.PlacementArea, w.screen, workspace.currentDesktop);
+    var g = w.frameGeometry;
+    g.width = resolve_geometry("{{{width}}}", g.width, area.width);
+    g.height = resolve_geometry("{{{height}}}", g.height, area.height);
+    w.frameGeometry = g;
+})();"#;
+
+/// `get_desktop`/`set_desktop` act on `workspace.currentDesktop` rather than
+/// a window, so they're workspace-level steps rather than `ACTIONS` entries.
+/// KWin 6 represents desktops as `VirtualDesktop` objects (looked up from
+/// `workspace.desktops`) where KWin 5 used plain 1-based integers.
+const STEP_GET_DESKTOP: &str = r#"
+    output_debug("STEP get_desktop")
+    {{#if kde5}}
+    output_result(workspace.currentDesktop);
+    {{else}}
+    output_result(workspace.currentDesktop.x11DesktopNumber);
+    {{/if}}
+"#;
+
+const STEP_SET_DESKTOP: &str = r#"
+    output_debug("STEP set_desktop {{{desktop}}}")
+    {{#if kde5}}
+    workspace.currentDesktop = {{{desktop}}};
+    {{else}}
+    workspace.currentDesktop = workspace.desktops[{{{desktop}}} - 1];
+    {{/if}}
+"#;
+
+/// Action body for `set_desktop_for_window`; takes the target desktop
+/// number as an extra argument, like `ACTION_WINDOWMOVE`/`ACTION_WINDOWSIZE`.
+const ACTION_SET_DESKTOP_FOR_WINDOW: &str = r#"{{#if kde5}}w.desktop = {{{desktop}}};{{else}}w.desktops = [workspace.desktops[{{{desktop}}} - 1]];{{/if}}"#;
+
 static ACTIONS: phf::Map<&'static str, &'static str> = phf_map! {
     "getwindowname" => "output_result(w.caption);",
     "getwindowclassname" => "output_result(w.resourceClass);",
@@ -129,14 +357,43 @@ static ACTIONS: phf::Map<&'static str, &'static str> = phf_map! {
     "windowclose" => "w.closeWindow();",
     "windowkill" => "w.killWindow();",
     "windowactivate" => "workspace.setActiveWindow(w);",
+    "get_desktop_for_window" => "{{#if kde5}}output_result(w.desktop);{{else}}output_result((w.desktops.length > 0) ? w.desktops[0].x11DesktopNumber : -1);{{/if}}",
 };
 
+/// One line of output produced by running a generated KWin script, tagged
+/// with the channel it came from so the client can route it to the right
+/// stream (or drop it, for debug output when `--debug` wasn't requested).
+#[derive(Debug, Clone)]
+enum OutputLine {
+    Result(String),
+    Error(String),
+    Debug(String),
+}
+
 struct Context {
     cmdline: Box<Parser>,
     debug: bool,
     dry_run: bool,
+    daemon: bool,
     kde5: bool,
-    marker: String,
+    back_channel_service: String,
+    back_channel_path: String,
+    /// Menu program used by `selectwindow` to let the user pick a window,
+    /// e.g. `dmenu` or `rofi -dmenu`. Defaults to `$KDOTOOL_MENU` or `dmenu`.
+    menu: String,
+    /// Window id the next `ACTIONS` command should act on if the user didn't
+    /// supply one explicitly. Normally `%1` (the top of `window_stack`); set
+    /// to a concrete `internalId` by `selectwindow` once the user has picked
+    /// a window interactively.
+    default_window_arg: String,
+    /// The daemon's focus-history tracker, shared with `spawn_mru_monitor`.
+    /// `None` in the one-shot (non-daemon) path, where `lru`/
+    /// `switch-urgent-or-lru` have no history to draw on.
+    mru: Option<Arc<Mutex<Vec<String>>>>,
+}
+
+fn default_menu() -> String {
+    std::env::var("KDOTOOL_MENU").unwrap_or_else(|_| "dmenu".to_string())
 }
 
 fn next_arg_is_option(cmdline : &mut Parser) -> bool {
@@ -150,15 +407,26 @@ fn next_arg_is_option(cmdline : &mut Parser) -> bool {
     }
 }
 
-fn generate_script(context : &mut Context) -> anyhow::Result<String> {
+/// Consume the next command-line token as a plain positional value, for
+/// commands like `windowmove`/`windowsize` that take several of them instead
+/// of the single optional window spec the `ACTIONS` branch assumes.
+fn next_value_arg(cmdline: &mut Parser, what: &str) -> anyhow::Result<String> {
+    cmdline.value()
+        .map(|val| val.to_string_lossy().into())
+        .map_err(|_| anyhow::anyhow!("Missing {} argument", what))
+}
+
+fn generate_script(conn: &Connection, context : &mut Context) -> anyhow::Result<String> {
     use lexopt::prelude::*;
 
     let mut result = String::new();
     let reg = Handlebars::new();
     let render_context = json!({
-        "marker": context.marker,
         "kde5": context.kde5,
-        "debug": context.debug
+        "debug": context.debug,
+        "back_channel_service": context.back_channel_service,
+        "back_channel_path": context.back_channel_path,
+        "back_channel_interface": BACK_CHANNEL_INTERFACE,
     });
 
     result.push_str(&reg.render_template(SCRIPT_HEADER, &render_context)?);
@@ -190,9 +458,127 @@ fn generate_script(context : &mut Context) -> anyhow::Result<String> {
                         last_step_is_query = true;
                     },
 
+                    "lru" | "switch-urgent-or-lru" => {
+                        let mru = context.mru.clone()
+                            .ok_or_else(|| anyhow::anyhow!("'{}' requires a running `kdotool --daemon` to track window focus history", command))?;
+                        let mru_ids_json = serde_json::to_string(&*mru.lock().unwrap())?;
+
+                        if command == "lru" {
+                            result.push_str(&reg.render_template(STEP_LRU_PUSH, &json!({"kde5": context.kde5, "mru_ids_json": mru_ids_json}))?);
+                            last_step_is_query = true;
+                        } else {
+                            result.push_str(&reg.render_template(STEP_SWITCH_URGENT_OR_LRU, &json!({"kde5": context.kde5, "mru_ids_json": mru_ids_json}))?);
+                            last_step_is_query = false;
+                        }
+                    },
+
+                    "selectwindow" => {
+                        let candidates = query_selectwindow_candidates(conn, context.kde5)?;
+                        if candidates.is_empty() {
+                            return Err(anyhow::anyhow!("No windows available to select from"));
+                        }
+
+                        let numbered_labels = number_candidate_labels(
+                            &candidates.iter().map(|(_, label)| label.as_str()).collect::<Vec<_>>()
+                        );
+                        let labels: Vec<&str> = numbered_labels.iter().map(|label| label.as_str()).collect();
+                        let selection = run_menu_picker(&context.menu, &labels)?;
+                        let index = parse_numbered_selection(&selection)?;
+                        let chosen_id = candidates.into_iter().nth(index)
+                            .map(|(id, _)| id)
+                            .ok_or_else(|| anyhow::anyhow!("Selection '{}' was not one of the offered windows", selection))?;
+
+                        result.push_str(&reg.render_template(STEP_SELECTWINDOW_PUSH, &json!({"kde5": context.kde5, "window_id": chosen_id}))?);
+                        context.default_window_arg = chosen_id;
+                        last_step_is_query = true;
+                    },
+
+                    // Emitted by `resolve_selectwindow_client_side` in place of
+                    // `selectwindow` when forwarding to a daemon: the menu has
+                    // already been shown client-side (so the daemon's single
+                    // request-handling thread never blocks on it) and `window_id`
+                    // is the id the user picked.
+                    "__selectwindow" => {
+                        let chosen_id = next_value_arg(&mut context.cmdline, "window id")?;
+                        result.push_str(&reg.render_template(STEP_SELECTWINDOW_PUSH, &json!({"kde5": context.kde5, "window_id": chosen_id}))?);
+                        context.default_window_arg = chosen_id;
+                        last_step_is_query = true;
+                    },
+
+                    "windowmove" | "windowsize" => {
+                        let window_arg = next_value_arg(&mut context.cmdline, "window")?;
+                        let arg_a = next_value_arg(&mut context.cmdline, if command == "windowmove" { "x" } else { "width" })?;
+                        let arg_b = next_value_arg(&mut context.cmdline, if command == "windowmove" { "y" } else { "height" })?;
+
+                        let action = if command == "windowmove" {
+                            reg.render_template(ACTION_WINDOWMOVE, &json!({"x": arg_a, "y": arg_b}))?
+                        } else {
+                            reg.render_template(ACTION_WINDOWSIZE, &json!({"width": arg_a, "height": arg_b}))?
+                        };
+
+                        if window_arg == "%@" {
+                            result.push_str(&reg.render_template(STEP_ACTION_ON_STACK_ALL, &json!({"step_name": command, "action": action}))?);
+                        } else if window_arg.starts_with('%') {
+                            let index = window_arg[1..].parse::<i32>()?;
+                            result.push_str(&reg.render_template(STEP_ACTION_ON_STACK_ITEM, &json!({"step_name": command, "action": action, "item_index": index}))?);
+                        } else {
+                            result.push_str(&reg.render_template(STEP_ACTION_ON_WINDOW_ID, &json!({"step_name": command, "action": action, "window_id": window_arg}))?);
+                        }
+
+                        last_step_is_query = false;
+                    },
+
+                    "get_desktop" => {
+                        result.push_str(&reg.render_template(STEP_GET_DESKTOP, &render_context)?);
+                        last_step_is_query = true;
+                    },
+
+                    "set_desktop" => {
+                        let desktop_arg = next_value_arg(&mut context.cmdline, "desktop")?;
+                        let desktop: i32 = desktop_arg.parse()
+                            .map_err(|_| anyhow::anyhow!("Invalid desktop number '{}'", desktop_arg))?;
+                        result.push_str(&reg.render_template(STEP_SET_DESKTOP, &json!({"kde5": context.kde5, "desktop": desktop}))?);
+                    },
+
+                    "set_desktop_for_window" => {
+                        let window_arg = next_value_arg(&mut context.cmdline, "window")?;
+                        let desktop_arg = next_value_arg(&mut context.cmdline, "desktop")?;
+                        let desktop: i32 = desktop_arg.parse()
+                            .map_err(|_| anyhow::anyhow!("Invalid desktop number '{}'", desktop_arg))?;
+                        let action = reg.render_template(ACTION_SET_DESKTOP_FOR_WINDOW, &json!({"kde5": context.kde5, "desktop": desktop}))?;
+
+                        if window_arg == "%@" {
+                            result.push_str(&reg.render_template(STEP_ACTION_ON_STACK_ALL, &json!({"step_name": command, "action": action}))?);
+                        } else if window_arg.starts_with('%') {
+                            let index = window_arg[1..].parse::<i32>()?;
+                            result.push_str(&reg.render_template(STEP_ACTION_ON_STACK_ITEM, &json!({"step_name": command, "action": action, "item_index": index}))?);
+                        } else {
+                            result.push_str(&reg.render_template(STEP_ACTION_ON_WINDOW_ID, &json!({"step_name": command, "action": action, "window_id": window_arg}))?);
+                        }
+
+                        last_step_is_query = false;
+                    },
+
                     _ => {
                         if ACTIONS.contains_key(command.as_ref()) {
-                            let mut arg1 = "%1".to_string();
+                            // Known limitation: an explicit window argument
+                            // (a literal window id such as an internalId, or
+                            // a `%N`/`%@` stack selector) never starts with
+                            // "-", so
+                            // `next_arg_is_option` never treats it as
+                            // something to consume here, and it's left for
+                            // the outer loop to misread as a new top-level
+                            // command -- `kdotool windowactivate 0x12345`
+                            // fails today rather than acting on that window.
+                            // Disambiguating "trailing explicit window id"
+                            // from "next chained command" needs a real rule
+                            // (e.g. checking the token against the set of
+                            // known command names) rather than a guess, and
+                            // touching it here requires keeping
+                            // resolve_selectwindow_client_side's mirror of
+                            // this branch in sync, so it's left as a known
+                            // gap rather than patched speculatively.
+                            let mut arg1 = context.default_window_arg.clone();
                             while next_arg_is_option(&mut context.cmdline) {
                                 let arg = context.cmdline.next()?.unwrap();
                                 match arg {
@@ -237,27 +623,560 @@ fn generate_script(context : &mut Context) -> anyhow::Result<String> {
     Ok(result)
 }
 
-fn main() -> anyhow::Result<()> {
-    use lexopt::prelude::*;
+fn assign_back_channel(context: &mut Context) {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    context.back_channel_service = format!("org.kde.kdotool.{}", std::process::id());
+    context.back_channel_path = format!("/Output/{}", id);
+}
 
-    env_logger::init();
+/// Run the command stream held by `context` against a live KWin session and
+/// return the output it produced. This is the shared core used both by the
+/// one-shot `kdotool <command>` invocation and by the daemon, which calls it
+/// once per client request while keeping `conn` open across requests.
+fn run_commands(conn: &Connection, context: &mut Context) -> anyhow::Result<Vec<OutputLine>> {
+    log::debug!("===== Register back channel =====");
+    assign_back_channel(context);
+
+    log::debug!("===== Generate KWin script =====");
+    let script_contents = generate_script(conn, context)?;
+
+    if context.dry_run {
+        println!("{}", script_contents);
+        return Ok(Vec::new());
+    }
+
+    execute_script(conn, context, &script_contents)
+}
+
+/// Load `script_contents` into KWin, run it, and collect whatever it reports
+/// over the `Emit()` back channel that `context.back_channel_service`/
+/// `back_channel_path` were rendered into the script with.
+///
+/// Known limitation: this calls `loadScript` fresh on every invocation, daemon
+/// or not. A resident script can keep reacting to KWin signals indefinitely
+/// (see `MONITOR_SCRIPT`/`spawn_mru_monitor`), but there's no way for one
+/// already-loaded script to pull in a *different* command stream to run each
+/// time -- `loadScript` parses a fixed file once, and re-running the same
+/// `Script` object just re-executes that same fixed body. Since every
+/// invocation's command stream (and back-channel address) differs, avoiding
+/// the repeated `loadScript` call here would need a generic way to dispatch
+/// new code into an already-running script, which this scripting API doesn't
+/// expose. The daemon amortizes process startup and D-Bus connection setup
+/// (see `run_daemon`'s doc comment), but not the `loadScript` call itself.
+fn execute_script(conn: &Connection, context: &Context, script_contents: &str) -> anyhow::Result<Vec<OutputLine>> {
+    log::debug!("Script:{}", script_contents);
+
+    let mut script_file = NamedTempFile::with_prefix("kdotool-")?;
+    script_file.write_all(script_contents.as_bytes())?;
+    let script_file_path = script_file.into_temp_path();
+
+    log::debug!("===== Set up Emit() receiver =====");
+    conn.request_name(&context.back_channel_service, false, true, false)?;
+
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let finished = Rc::new(Cell::new(false));
+
+    let mut cr = Crossroads::new();
+    let iface_token = {
+        let output = output.clone();
+        let finished = finished.clone();
+        cr.register(BACK_CHANNEL_INTERFACE, move |b| {
+            let output = output.clone();
+            let finished = finished.clone();
+            b.method("Emit", ("kind", "message"), (), move |_, _, (kind, message): (String, String)| {
+                match kind.as_str() {
+                    "RESULT" => output.borrow_mut().push(OutputLine::Result(message)),
+                    "ERROR" => output.borrow_mut().push(OutputLine::Error(message)),
+                    "DEBUG" => output.borrow_mut().push(OutputLine::Debug(message)),
+                    "FINISH" => finished.set(true),
+                    _ => {},
+                }
+                Ok(())
+            });
+        })
+    };
+    cr.insert(context.back_channel_path.clone(), &[iface_token], ());
+
+    log::debug!("===== Load script into KWin =====");
+    let kwin_proxy = conn.with_proxy("org.kde.KWin", "/Scripting", Duration::from_millis(5000));
+    let (script_id,): (i32,) = kwin_proxy.method_call("org.kde.kwin.Scripting", "loadScript", (script_file_path.to_str().unwrap(),))?;
+    log::debug!("Script ID: {}", script_id);
+
+    log::debug!("===== Run script =====");
+    let script_proxy = conn.with_proxy("org.kde.KWin", format!("/Scripting/Script{}", script_id), Duration::from_millis(5000));
+    script_proxy.method_call("org.kde.kwin.Script", "run", ())?;
+    script_proxy.method_call("org.kde.kwin.Script", "stop", ())?;
+
+    log::debug!("===== Collect output over the back channel =====");
+    let deadline = Instant::now() + BACK_CHANNEL_TIMEOUT;
+    while !finished.get() {
+        conn.channel().read_write(Some(Duration::from_millis(200))).ok();
+        while let Some(msg) = conn.channel().pop_message() {
+            let _ = cr.handle_message(msg, conn.channel());
+        }
+        if Instant::now() >= deadline {
+            anyhow::bail!("Timed out waiting for KWin script output on {}", context.back_channel_path);
+        }
+    }
+
+    let _ = conn.release_name(&context.back_channel_service);
+
+    Ok(Rc::try_unwrap(output).map_err(|_| anyhow::anyhow!("back channel output still borrowed"))?.into_inner())
+}
+
+fn print_output(output: &[OutputLine]) {
+    for line in output {
+        match line {
+            OutputLine::Result(text) => println!("{}", text),
+            OutputLine::Error(text) => eprintln!("{}", text),
+            OutputLine::Debug(text) => log::debug!("{}", text),
+        }
+    }
+}
+
+/// Path of the Unix socket the daemon listens on and clients connect to.
+/// Scoped per-user under `XDG_RUNTIME_DIR` (falling back to `/tmp`) so
+/// multiple sessions on the same machine don't collide.
+fn daemon_socket_path() -> std::path::PathBuf {
+    let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".to_string());
+    std::path::Path::new(&runtime_dir).join("kdotool.sock")
+}
+
+/// Run as the long-lived daemon: keep one KWin `Connection` open and serve
+/// client requests from `try_run_via_daemon` over a Unix socket, so callers
+/// skip process startup and D-Bus connection setup on every invocation.
+/// Each request still loads and runs its own generated script (its command
+/// stream differs per call, so there's nothing fixed to cache), but none of
+/// it is scraped from the journal any more -- see `execute_script`.
+fn run_daemon() -> anyhow::Result<()> {
+    let socket_path = daemon_socket_path();
+    if socket_path.exists() {
+        std::fs::remove_file(&socket_path)?;
+    }
+
+    let conn = Connection::new_session()?;
+    let listener = UnixListener::bind(&socket_path)?;
+    log::info!("kdotool daemon listening on {}", socket_path.display());
+
+    let mru = spawn_mru_monitor(kde5_session())?;
+
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                log::debug!("Failed to accept client connection: {}", err);
+                continue;
+            }
+        };
+
+        if let Err(err) = handle_daemon_client(&conn, &mru, &mut stream) {
+            log::debug!("Error handling client request: {}", err);
+            let _ = writeln!(stream, "ERROR {}", err);
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_daemon_client(conn: &Connection, mru: &Arc<Mutex<Vec<String>>>, stream: &mut UnixStream) -> anyhow::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let args: Vec<String> = serde_json::from_str(line.trim_end())?;
 
     let mut context = Context {
-        cmdline: Box::new(Parser::from_env()),
+        cmdline: Box::new(Parser::from_args(args)),
         debug: false,
         dry_run: false,
-        kde5: false,
-        marker: String::new(),
+        daemon: false,
+        kde5: kde5_session(),
+        back_channel_service: String::new(),
+        back_channel_path: String::new(),
+        menu: default_menu(),
+        default_window_arg: "%1".to_string(),
+        mru: Some(mru.clone()),
+    };
+    parse_global_options(&mut context)?;
+
+    let output = run_commands(conn, &mut context)?;
+    for line in output {
+        match line {
+            OutputLine::Result(text) => writeln!(stream, "RESULT {}", text)?,
+            OutputLine::Error(text) => writeln!(stream, "ERROR {}", text)?,
+            OutputLine::Debug(text) => writeln!(stream, "DEBUG {}", text)?,
+        }
+    }
+    writeln!(stream, "FINISH")?;
+
+    Ok(())
+}
+
+/// Load the resident MRU-tracking script into KWin on a dedicated
+/// connection and return the list it maintains: oldest-focused first, most
+/// recently focused last. The script is never stopped, so its
+/// `windowActivated`/`clientActivated` handler keeps reporting focus changes
+/// for as long as the daemon runs. Entries are also dropped as soon as their
+/// window closes (`windowRemoved`/`clientRemoved`), so `switch-urgent-or-lru`
+/// never targets a window that's already gone.
+fn spawn_mru_monitor(kde5: bool) -> anyhow::Result<Arc<Mutex<Vec<String>>>> {
+    let mru = Arc::new(Mutex::new(Vec::new()));
+
+    let conn = Connection::new_session()?;
+    let service = format!("org.kde.kdotool.{}.monitor", std::process::id());
+    conn.request_name(&service, false, true, false)?;
+
+    let reg = Handlebars::new();
+    let script_contents = reg.render_template(MONITOR_SCRIPT, &json!({
+        "kde5": kde5,
+        "back_channel_service": service,
+        "back_channel_path": "/Monitor",
+        "back_channel_interface": BACK_CHANNEL_INTERFACE,
+    }))?;
+    log::debug!("MRU monitor script:{}", script_contents);
+
+    let mut script_file = NamedTempFile::with_prefix("kdotool-monitor-")?;
+    script_file.write_all(script_contents.as_bytes())?;
+    let script_file_path = script_file.into_temp_path();
+
+    let kwin_proxy = conn.with_proxy("org.kde.KWin", "/Scripting", Duration::from_millis(5000));
+    let (script_id,): (i32,) = kwin_proxy.method_call("org.kde.kwin.Scripting", "loadScript", (script_file_path.to_str().unwrap(),))?;
+    let script_proxy = conn.with_proxy("org.kde.KWin", format!("/Scripting/Script{}", script_id), Duration::from_millis(5000));
+    script_proxy.method_call("org.kde.kwin.Script", "run", ())?;
+
+    let mut cr = Crossroads::new();
+    let token = {
+        let mru = mru.clone();
+        cr.register(BACK_CHANNEL_INTERFACE, move |b| {
+            let mru = mru.clone();
+            b.method("Emit", ("kind", "message"), (), move |_, _, (kind, message): (String, String)| {
+                match kind.as_str() {
+                    "ACTIVATED" => {
+                        let mut mru = mru.lock().unwrap();
+                        mru.retain(|id| id != &message);
+                        mru.push(message);
+                    },
+                    "REMOVED" => {
+                        mru.lock().unwrap().retain(|id| id != &message);
+                    },
+                    _ => {},
+                }
+                Ok(())
+            });
+        })
     };
+    cr.insert("/Monitor", &[token], ());
 
-    match std::env::var("KDE_SESSION_VERSION") {
-        Ok(version) => {
-            if version == "5" {
-                context.kde5 = true;
+    std::thread::spawn(move || {
+        loop {
+            if conn.channel().read_write(Some(Duration::from_millis(500))).is_ok() {
+                while let Some(msg) = conn.channel().pop_message() {
+                    let _ = cr.handle_message(msg, conn.channel());
+                }
             }
+        }
+    });
+
+    Ok(mru)
+}
+
+/// Render `body` between the usual script header/footer and run it,
+/// collecting its output. Used for small ad-hoc queries (the `selectwindow`
+/// candidate list) that don't go through the `generate_script()`
+/// command-stream parser.
+fn run_script_body(conn: &Connection, kde5: bool, body: &str) -> anyhow::Result<Vec<OutputLine>> {
+    let mut context = Context {
+        cmdline: Box::new(Parser::from_args(Vec::<String>::new())),
+        debug: false,
+        dry_run: false,
+        daemon: false,
+        kde5,
+        back_channel_service: String::new(),
+        back_channel_path: String::new(),
+        menu: default_menu(),
+        default_window_arg: "%1".to_string(),
+        mru: None,
+    };
+    assign_back_channel(&mut context);
+
+    let reg = Handlebars::new();
+    let render_context = json!({
+        "kde5": context.kde5,
+        "debug": context.debug,
+        "back_channel_service": context.back_channel_service,
+        "back_channel_path": context.back_channel_path,
+        "back_channel_interface": BACK_CHANNEL_INTERFACE,
+    });
+
+    let mut script_contents = reg.render_template(SCRIPT_HEADER, &render_context)?;
+    script_contents.push_str(&reg.render_template(body, &render_context)?);
+    script_contents.push_str(&reg.render_template(SCRIPT_FOOTER, &render_context)?);
+
+    execute_script(conn, &context, &script_contents)
+}
+
+/// Query every window's `internalId` and display label, for `selectwindow`
+/// to offer through the external menu program.
+fn query_selectwindow_candidates(conn: &Connection, kde5: bool) -> anyhow::Result<Vec<(String, String)>> {
+    let output = run_script_body(conn, kde5, STEP_SELECTWINDOW_CANDIDATES)?;
+    Ok(output.into_iter().filter_map(|line| match line {
+        OutputLine::Result(text) => {
+            let (id, label) = text.split_once('\t')?;
+            Some((id.to_string(), label.to_string()))
         },
-        Err(_) => {},
+        _ => None,
+    }).collect())
+}
+
+/// Spawn `menu` (e.g. `dmenu` or `rofi -dmenu`), feed it `labels` on stdin
+/// (one per line), and return whichever line it printed back on stdout.
+fn run_menu_picker(menu: &str, labels: &[&str]) -> anyhow::Result<String> {
+    let mut parts = menu.split_whitespace();
+    let program = parts.next().ok_or_else(|| anyhow::anyhow!("Empty --menu command"))?;
+
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    let mut stdin = child.stdin.take().unwrap();
+    for label in labels {
+        writeln!(stdin, "{}", label)?;
     }
+    drop(stdin);
+
+    let output = child.wait_with_output()?;
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Parse just the `--menu` global option out of `args`, ignoring the rest of
+/// the command stream. Reuses `parse_global_options` against a throwaway
+/// `Context` rather than re-implementing option parsing.
+fn client_side_menu(args: &[String]) -> anyhow::Result<String> {
+    let mut context = Context {
+        cmdline: Box::new(Parser::from_args(args.to_vec())),
+        debug: false,
+        dry_run: false,
+        daemon: false,
+        kde5: false,
+        back_channel_service: String::new(),
+        back_channel_path: String::new(),
+        menu: default_menu(),
+        default_window_arg: "%1".to_string(),
+        mru: None,
+    };
+    parse_global_options(&mut context)?;
+    Ok(context.menu)
+}
+
+/// If `args` invokes `selectwindow` (possibly more than once in a chained
+/// command list), show the menu and resolve each chosen window's id right
+/// here in the client process -- before anything reaches a daemon -- and
+/// rewrite each `selectwindow` into the internal `__selectwindow <id>` step
+/// so the daemon can apply the selection without any further interactivity.
+/// Without this, the menu picker (which can be an interactive terminal
+/// program like `fzf`) would run on the daemon's single request-handling
+/// thread instead of the caller's own terminal/session, and would block
+/// every other client for as long as the user takes to pick.
+///
+/// Walks the command stream the same way `parse_global_options`/
+/// `generate_script` do, consuming each command's own positional arguments
+/// as it goes, rather than matching the string "selectwindow" anywhere in
+/// argv -- so a command whose argument happens to be that literal string
+/// (e.g. `search selectwindow`) is left alone.
+fn resolve_selectwindow_client_side(args: &[String]) -> anyhow::Result<Vec<String>> {
+    use lexopt::prelude::*;
+
+    if !args.iter().any(|a| a == "selectwindow") {
+        return Ok(args.to_vec());
+    }
+
+    let mut cmdline = Parser::from_args(args.to_vec());
+    let mut result = Vec::new();
+
+    while next_arg_is_option(&mut cmdline) {
+        match cmdline.next()?.unwrap() {
+            Short('d') | Long("debug") => result.push("--debug".to_string()),
+            Short('n') | Long("dry-run") => result.push("--dry-run".to_string()),
+            Long("menu") => {
+                result.push("--menu".to_string());
+                result.push(cmdline.value()?.to_string_lossy().into());
+            },
+            arg => return Err(arg.unexpected().into()),
+        }
+    }
+
+    let menu = client_side_menu(args)?;
+    let mut conn: Option<Connection> = None;
+
+    while let Some(arg) = cmdline.next()? {
+        match arg {
+            Value(val) => {
+                let command: String = val.to_string_lossy().into();
+                match command.as_ref() {
+                    "search" => {
+                        result.push(command);
+                        match cmdline.next()? {
+                            Some(Value(term)) => result.push(term.to_string_lossy().into()),
+                            _ => return Err(anyhow::anyhow!("Missing search term")),
+                        }
+                    },
+                    "windowmove" | "windowsize" => {
+                        result.push(command);
+                        for what in ["window", "x/width", "y/height"] {
+                            result.push(next_value_arg(&mut cmdline, what)?);
+                        }
+                    },
+                    "set_desktop" => {
+                        result.push(command);
+                        result.push(next_value_arg(&mut cmdline, "desktop")?);
+                    },
+                    "set_desktop_for_window" => {
+                        result.push(command);
+                        result.push(next_value_arg(&mut cmdline, "window")?);
+                        result.push(next_value_arg(&mut cmdline, "desktop")?);
+                    },
+                    "__selectwindow" => {
+                        result.push(command);
+                        result.push(next_value_arg(&mut cmdline, "window id")?);
+                    },
+                    "selectwindow" => {
+                        if conn.is_none() {
+                            conn = Some(Connection::new_session()?);
+                        }
+
+                        let candidates = query_selectwindow_candidates(conn.as_ref().unwrap(), kde5_session())?;
+                        if candidates.is_empty() {
+                            return Err(anyhow::anyhow!("No windows available to select from"));
+                        }
+
+                        let numbered_labels = number_candidate_labels(
+                            &candidates.iter().map(|(_, label)| label.as_str()).collect::<Vec<_>>()
+                        );
+                        let labels: Vec<&str> = numbered_labels.iter().map(|label| label.as_str()).collect();
+                        let selection = run_menu_picker(&menu, &labels)?;
+                        let index = parse_numbered_selection(&selection)?;
+                        let chosen_id = candidates.into_iter().nth(index)
+                            .map(|(id, _)| id)
+                            .ok_or_else(|| anyhow::anyhow!("Selection '{}' was not one of the offered windows", selection))?;
+
+                        result.push("__selectwindow".to_string());
+                        result.push(chosen_id);
+                    },
+                    _ => {
+                        // Every other command takes no positional argument of
+                        // its own except an optional window spec, consumed
+                        // the same way generate_script's generic ACTIONS
+                        // branch consumes it -- only when the next raw token
+                        // looks like an option. That means, here as in
+                        // generate_script, an explicit window argument
+                        // (which never starts with "-") is left in the
+                        // stream rather than consumed; this walker passes it
+                        // through untouched rather than dropping it, so the
+                        // token still reaches generate_script to be
+                        // (mis-)interpreted exactly as it would be without
+                        // this client-side pass at all. Making this walker
+                        // disambiguate "trailing window id" from "next
+                        // chained command" on its own, without also fixing
+                        // generate_script's identical branch, would make the
+                        // two disagree about where one command's arguments
+                        // end -- so it isn't done here; see generate_script's
+                        // ACTIONS arm for the pre-existing limitation itself.
+                        result.push(command);
+                        while next_arg_is_option(&mut cmdline) {
+                            match cmdline.next()?.unwrap() {
+                                Value(val) => result.push(val.to_string_lossy().into()),
+                                _ => return Err(anyhow::anyhow!("Unexpected option")),
+                            }
+                        }
+                    },
+                }
+            },
+            _ => return Err(anyhow::anyhow!("Unexpected option")),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Forward this invocation's argv to a running daemon over its Unix socket
+/// and print back whatever it reports. Returns `Ok(false)` (without having
+/// printed anything) if no daemon is listening, so the caller can fall back
+/// to the one-shot path.
+fn try_run_via_daemon(args: &[String]) -> anyhow::Result<bool> {
+    let stream = match UnixStream::connect(daemon_socket_path()) {
+        Ok(stream) => stream,
+        Err(_) => return Ok(false),
+    };
+
+    let args = resolve_selectwindow_client_side(args)?;
+
+    let mut writer = stream.try_clone()?;
+    writeln!(writer, "{}", serde_json::to_string(&args)?)?;
+
+    let reader = BufReader::new(stream);
+    for line in reader.lines() {
+        let line = line?;
+        if line == "FINISH" {
+            break;
+        } else if let Some(text) = line.strip_prefix("RESULT ") {
+            println!("{}", text);
+        } else if let Some(text) = line.strip_prefix("ERROR ") {
+            eprintln!("{}", text);
+        } else if let Some(text) = line.strip_prefix("DEBUG ") {
+            log::debug!("{}", text);
+        }
+    }
+
+    Ok(true)
+}
+
+fn kde5_session() -> bool {
+    matches!(std::env::var("KDE_SESSION_VERSION"), Ok(version) if version == "5")
+}
+
+fn parse_global_options(context: &mut Context) -> anyhow::Result<()> {
+    use lexopt::prelude::*;
+
+    while next_arg_is_option(&mut context.cmdline) {
+        let arg = context.cmdline.next()?.unwrap();
+        match arg {
+            Short('d') | Long("debug") => {
+                context.debug = true;
+            },
+            Short('n') | Long("dry-run") => {
+                context.dry_run = true;
+            },
+            Long("menu") => {
+                context.menu = context.cmdline.value()?.to_string_lossy().into();
+            },
+            _ => {
+                return Err(arg.unexpected().into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    use lexopt::prelude::*;
+
+    env_logger::init();
+
+    let mut context = Context {
+        cmdline: Box::new(Parser::from_env()),
+        debug: false,
+        dry_run: false,
+        daemon: false,
+        kde5: kde5_session(),
+        back_channel_service: String::new(),
+        back_channel_path: String::new(),
+        menu: default_menu(),
+        default_window_arg: "%1".to_string(),
+        mru: None,
+    };
 
     // Parse global options
     if context.cmdline.try_raw_args().unwrap().peek().is_none() {
@@ -278,59 +1197,36 @@ fn main() -> anyhow::Result<()> {
             Short('n') | Long("dry-run") => {
                 context.dry_run = true;
             },
+            Long("daemon") => {
+                context.daemon = true;
+            },
+            Long("menu") => {
+                context.menu = context.cmdline.value()?.to_string_lossy().into();
+            },
             _ => {
                 return Err(arg.unexpected().into());
             }
         }
     }
 
-    log::debug!("===== Generate KWin script =====");
-    let mut script_file = NamedTempFile::with_prefix("kdotool-")?;
-    context.marker = script_file.path().file_name().unwrap().to_str().unwrap().to_string();
-
-    let script_contents = generate_script(&mut context)?;
-
-    log::debug!("Script:{}", script_contents);
-    script_file.write_all(script_contents.as_bytes())?;
-    let script_file_path = script_file.into_temp_path();
-
-    log::debug!("===== Load script into KWin =====");
-    let conn = Connection::new_session()?;
-    let kwin_proxy = conn.with_proxy("org.kde.KWin", "/Scripting", Duration::from_millis(5000));
-    let (script_id,): (i32,) = kwin_proxy.method_call("org.kde.kwin.Scripting", "loadScript", (script_file_path.to_str().unwrap(),))?;
-    log::debug!("Script ID: {}", script_id);
-
-    log::debug!("===== Run script =====");
-    let script_proxy = conn.with_proxy("org.kde.KWin", format!("/Scripting/Script{}", script_id), Duration::from_millis(5000));
-    let start_time = chrono::Local::now();
-    script_proxy.method_call("org.kde.kwin.Script", "run", ())?;
-    script_proxy.method_call("org.kde.kwin.Script", "stop", ())?;
+    if context.daemon {
+        return run_daemon();
+    }
 
-    let journal = Command::new("journalctl")
-        .arg(format!("--since={}", start_time.format("%Y-%m-%d %H:%M:%S")))
-        .arg("--user")
-        .arg("--unit=plasma-kwin_wayland.service")
-        .arg("--unit=plasma-kwin_x11.service")
-        .arg("--output=cat")
-        .output()?;
-    let output = String::from_utf8(journal.stdout)?;
-    log::debug!("KWin log from the systemd journal:\n{}", output.trim_end());
-
-    log::debug!("===== Output =====");
-    let script_marker = &format!("js: {} ", script_file_path.file_name().unwrap().to_str().unwrap());
-    for line in output.lines() {
-        if line.starts_with(script_marker) {
-            let t = &line[script_marker.len()..];
-            const RESULT: &str = "RESULT ";
-            const ERROR: &str = "ERROR ";
-            if t.starts_with(RESULT) {
-                println!("{}", &t[RESULT.len()..]);
-            } else if t.starts_with(ERROR) {
-                eprintln!("{}", &t[ERROR.len()..]);
-            }
+    // A long-lived daemon may already be running. Prefer forwarding to it so
+    // we skip re-establishing a KWin connection; fall back to the one-shot
+    // path transparently if nothing is listening.
+    if !context.dry_run {
+        let remaining_args: Vec<String> = std::env::args().skip(1).collect();
+        if try_run_via_daemon(&remaining_args)? {
+            return Ok(());
         }
     }
 
+    let conn = Connection::new_session()?;
+    let output = run_commands(&conn, &mut context)?;
+    print_output(&output);
+
     Ok(())
 }
 
@@ -341,10 +1237,13 @@ fn help() {
     println!("  -h, --help       Show this help");
     println!("  -d, --debug      Enable debug output");
     println!("  -n, --dry-run    Don't actually run the script. Just print it to stdout.");
+    println!("  --daemon         Run as a long-lived daemon other invocations forward to.");
+    println!("  --menu <cmd>     Menu program for 'selectwindow' (default: $KDOTOOL_MENU or dmenu)");
     println!();
     println!("Commands:");
     println!("  search <term>");
     println!("  getactivewindow");
+    println!("  selectwindow");
     println!("  getwindowname <window>");
     println!("  getwindowclassname <window>");
     println!("  getwindowgeometry <window>");
@@ -354,10 +1253,23 @@ fn help() {
     println!("  windowclose <window>");
     println!("  windowkill <window>");
     println!("  windowactivate <window>");
+    println!("  windowmove <window> <x> <y>");
+    println!("  windowsize <window> <width> <height>");
+    println!("  get_desktop");
+    println!("  set_desktop <desktop number>");
+    println!("  get_desktop_for_window <window>");
+    println!("  set_desktop_for_window <window> <desktop number>");
+    println!("  lru                       (requires --daemon)");
+    println!("  switch-urgent-or-lru      (requires --daemon)");
     println!();
     println!("Window can be specified as:");
     println!("  %1 - the first window in the stack (default)");
     println!("  %2 - the second window in the stack");
     println!("  %@ - all windows in the stack");
     println!("  <window id> - the window with the given ID");
+    println!();
+    println!("windowmove/windowsize coordinates can be given as:");
+    println!("  320     - an absolute pixel value");
+    println!("  +10/-10 - an offset relative to the window's current position/size");
+    println!("  50%     - a percentage of the window's screen width/height");
 }